@@ -1,12 +1,12 @@
+use sqlx::database::HasValueRef;
 use sqlx::encode::IsNull;
-use sqlx::sqlite::SqliteArgumentValue;
-use sqlx::sqlite::SqliteTypeInfo;
-use sqlx::Encode;
-use sqlx::Sqlite;
-use sqlx::Type;
+use sqlx::error::BoxDynError;
+use sqlx::sqlite::{SqliteArgumentValue, SqliteTypeInfo};
+use sqlx::{Decode, Encode, Sqlite, Type};
 
 use super::Pillid;
 
+#[cfg(not(feature = "sqlx-binary"))]
 impl<'q> Encode<'q, Sqlite> for Pillid {
     fn encode(self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
         <String as Encode<Sqlite>>::encode(self.to_string(), args)
@@ -17,8 +17,155 @@ impl<'q> Encode<'q, Sqlite> for Pillid {
     }
 }
 
+#[cfg(not(feature = "sqlx-binary"))]
 impl Type<Sqlite> for Pillid {
     fn type_info() -> SqliteTypeInfo {
         <&str as Type<Sqlite>>::type_info()
     }
 }
+
+#[cfg(not(feature = "sqlx-binary"))]
+impl<'r> Decode<'r, Sqlite> for Pillid {
+    fn decode(value: <Sqlite as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        let s = <String as Decode<Sqlite>>::decode(value)?;
+        s.parse::<Pillid>().map_err(Into::into)
+    }
+}
+
+/// Stores a Pillid as its packed 24-byte-plus-prefix form in a `BLOB` column
+/// instead of its base62 text, for smaller rows and a smaller index.
+#[cfg(feature = "sqlx-binary")]
+impl<'q> Encode<'q, Sqlite> for Pillid {
+    fn encode(self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        <Vec<u8> as Encode<Sqlite>>::encode(self.to_packed(), args)
+    }
+
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        <Vec<u8> as Encode<Sqlite>>::encode(self.to_packed(), args)
+    }
+}
+
+#[cfg(feature = "sqlx-binary")]
+impl Type<Sqlite> for Pillid {
+    fn type_info() -> SqliteTypeInfo {
+        <&[u8] as Type<Sqlite>>::type_info()
+    }
+}
+
+#[cfg(feature = "sqlx-binary")]
+impl<'r> Decode<'r, Sqlite> for Pillid {
+    fn decode(value: <Sqlite as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        let bytes = <Vec<u8> as Decode<Sqlite>>::decode(value)?;
+        Pillid::from_packed(&bytes).map_err(Into::into)
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use sqlx::database::HasValueRef;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo};
+    use sqlx::{Decode, Encode, Postgres, Type};
+
+    use super::Pillid;
+
+    #[cfg(not(feature = "sqlx-binary"))]
+    impl<'q> Encode<'q, Postgres> for Pillid {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+            <String as Encode<Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    #[cfg(not(feature = "sqlx-binary"))]
+    impl Type<Postgres> for Pillid {
+        fn type_info() -> PgTypeInfo {
+            <&str as Type<Postgres>>::type_info()
+        }
+    }
+
+    #[cfg(not(feature = "sqlx-binary"))]
+    impl<'r> Decode<'r, Postgres> for Pillid {
+        fn decode(value: <Postgres as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+            let s = <String as Decode<Postgres>>::decode(value)?;
+            s.parse::<Pillid>().map_err(Into::into)
+        }
+    }
+
+    #[cfg(feature = "sqlx-binary")]
+    impl<'q> Encode<'q, Postgres> for Pillid {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+            <Vec<u8> as Encode<Postgres>>::encode(self.to_packed(), buf)
+        }
+    }
+
+    #[cfg(feature = "sqlx-binary")]
+    impl Type<Postgres> for Pillid {
+        fn type_info() -> PgTypeInfo {
+            <&[u8] as Type<Postgres>>::type_info()
+        }
+    }
+
+    #[cfg(feature = "sqlx-binary")]
+    impl<'r> Decode<'r, Postgres> for Pillid {
+        fn decode(value: <Postgres as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+            let bytes = <Vec<u8> as Decode<Postgres>>::decode(value)?;
+            Pillid::from_packed(&bytes).map_err(Into::into)
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql {
+    use sqlx::database::HasValueRef;
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::mysql::{MySqlArgumentBuffer, MySqlTypeInfo};
+    use sqlx::{Decode, Encode, MySql, Type};
+
+    use super::Pillid;
+
+    #[cfg(not(feature = "sqlx-binary"))]
+    impl<'q> Encode<'q, MySql> for Pillid {
+        fn encode_by_ref(&self, buf: &mut MySqlArgumentBuffer) -> IsNull {
+            <String as Encode<MySql>>::encode(self.to_string(), buf)
+        }
+    }
+
+    #[cfg(not(feature = "sqlx-binary"))]
+    impl Type<MySql> for Pillid {
+        fn type_info() -> MySqlTypeInfo {
+            <&str as Type<MySql>>::type_info()
+        }
+    }
+
+    #[cfg(not(feature = "sqlx-binary"))]
+    impl<'r> Decode<'r, MySql> for Pillid {
+        fn decode(value: <MySql as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+            let s = <String as Decode<MySql>>::decode(value)?;
+            s.parse::<Pillid>().map_err(Into::into)
+        }
+    }
+
+    #[cfg(feature = "sqlx-binary")]
+    impl<'q> Encode<'q, MySql> for Pillid {
+        fn encode_by_ref(&self, buf: &mut MySqlArgumentBuffer) -> IsNull {
+            <Vec<u8> as Encode<MySql>>::encode(self.to_packed(), buf)
+        }
+    }
+
+    #[cfg(feature = "sqlx-binary")]
+    impl Type<MySql> for Pillid {
+        fn type_info() -> MySqlTypeInfo {
+            <&[u8] as Type<MySql>>::type_info()
+        }
+    }
+
+    #[cfg(feature = "sqlx-binary")]
+    impl<'r> Decode<'r, MySql> for Pillid {
+        fn decode(value: <MySql as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+            let bytes = <Vec<u8> as Decode<MySql>>::decode(value)?;
+            Pillid::from_packed(&bytes).map_err(Into::into)
+        }
+    }
+}
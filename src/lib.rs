@@ -7,16 +7,22 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use anyhow::Result;
+use chrono::DateTime;
 use chrono::Utc;
-use serde::Deserialize;
-use serde::Serialize;
+use ::serde::Deserialize;
+use ::serde::Serialize;
 use thiserror::Error;
 
+mod macros;
 mod rng;
+pub mod serde;
 
 #[cfg(feature = "sqlx")]
 mod db;
 
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+
 /// The maximum length of the prefix.
 const PREFIX_BYTES: usize = 32;
 
@@ -44,8 +50,32 @@ const RANDOM_LENGTH: usize = 22;
 /// The characters to use to generate an ID.
 const CHARSET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
 
-/// The maximum length of an ID.
-const MAX_LENGTH_PILLID: usize = PREFIX_LENGTH + TIMESTAMP_LENGTH + RANDOM_LENGTH;
+/// The maximum length of an ID: a full-length prefix, the `_` separator that
+/// follows it, the timestamp, and the random segment.
+const MAX_LENGTH_PILLID: usize = PREFIX_LENGTH + 1 + TIMESTAMP_LENGTH + RANDOM_LENGTH;
+
+/// Base62-encodes `n` into `output`, left-padding with `CHARSET[0]` to fill the slice.
+fn base62_encode(n: u128, output: &mut [u8]) {
+    let mut n = n;
+
+    for byte in output.iter_mut().rev() {
+        *byte = *CHARSET.get((n % 62) as usize).unwrap();
+        n /= 62;
+    }
+}
+
+/// Base62-decodes `digits`, reading left to right. Returns `None` if any byte
+/// is not a `CHARSET` member.
+fn base62_decode(digits: &[u8]) -> Option<u128> {
+    let mut acc: u128 = 0;
+
+    for &digit in digits {
+        let index = CHARSET.iter().position(|&c| c == digit)?;
+        acc = acc * 62 + index as u128;
+    }
+
+    Some(acc)
+}
 
 /// An ID that may be used to identify a resource.
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialOrd, PartialEq)]
@@ -59,6 +89,211 @@ impl Pillid {
     pub fn new(prefix: &str) -> Self {
         PillidBuilder::new().with_prefix(prefix).unwrap().build()
     }
+
+    /// Returns a validated, zero-copy view over this ID's segments.
+    pub fn parsed(&self) -> ParsedPillid<'_> {
+        ParsedPillid::new_unchecked(str_from_bytes(&self.0))
+    }
+
+    /// Returns the ID's prefix, or `None` if it was minted without one.
+    pub fn prefix(&self) -> Option<&str> {
+        self.parsed().prefix()
+    }
+
+    /// Returns the time this ID was minted at, to the nearest second.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.parsed().timestamp()
+    }
+
+    /// Returns the 128 bits of randomness this ID was minted with.
+    pub fn random(&self) -> [u8; RANDOMNESS_BYTES] {
+        self.parsed().random()
+    }
+
+    /// Strictly parses `s` into a `Pillid`, validating its structure instead
+    /// of assuming it was produced by this crate. Rejects the wrong length,
+    /// characters outside [`CHARSET`], and out-of-range timestamps.
+    ///
+    /// This is the same validation [`FromStr`] performs, since every way of
+    /// building a `Pillid` from an arbitrary string needs to uphold the
+    /// invariant [`Pillid::parsed`] relies on: a `Pillid`'s bytes are always
+    /// well-formed, never just short enough to fit the buffer.
+    pub fn parse(s: &str) -> Result<Self> {
+        s.parse()
+    }
+
+    /// Packs this id into a compact, non-human-readable form: a length byte
+    /// for the prefix, the prefix itself, the 8-byte big-endian timestamp,
+    /// and the 16 random bytes. Its 24-byte core is 4 bytes shorter than the
+    /// 28 base62 characters of the string, and it is what binary serde
+    /// formats (bincode, postcard, ...) use.
+    pub(crate) fn to_packed(&self) -> Vec<u8> {
+        let parsed = self.parsed();
+        let prefix = parsed.prefix().unwrap_or("");
+        let timestamp = parsed.timestamp().timestamp() as u64;
+        let random = parsed.random();
+
+        let mut packed = Vec::with_capacity(1 + prefix.len() + TIMESTAMP_BYTES + RANDOMNESS_BYTES);
+        packed.push(prefix.len() as u8);
+        packed.extend_from_slice(prefix.as_bytes());
+        packed.extend_from_slice(&timestamp.to_be_bytes());
+        packed.extend_from_slice(&random);
+        packed
+    }
+
+    /// Rebuilds a `Pillid` from the packed form produced by [`Pillid::to_packed`].
+    pub(crate) fn from_packed(packed: &[u8]) -> Result<Self, &'static str> {
+        let prefix_len = *packed.first().ok_or("packed Pillid is empty")? as usize;
+        let prefix_end = 1 + prefix_len;
+        let core_end = prefix_end + TIMESTAMP_BYTES + RANDOMNESS_BYTES;
+
+        if packed.len() != core_end {
+            return Err("packed Pillid has the wrong length");
+        }
+
+        let prefix = std::str::from_utf8(&packed[1..prefix_end])
+            .map_err(|_| "packed Pillid prefix is not valid UTF-8")?;
+        let timestamp = u64::from_be_bytes(
+            packed[prefix_end..prefix_end + TIMESTAMP_BYTES]
+                .try_into()
+                .unwrap(),
+        );
+        let random = u128::from_be_bytes(
+            packed[prefix_end + TIMESTAMP_BYTES..core_end]
+                .try_into()
+                .unwrap(),
+        );
+
+        Self::from_parts(prefix, timestamp, random)
+    }
+
+    /// Re-renders a `Pillid` from its decoded segments: a prefix, a Unix
+    /// timestamp in seconds, and 128 bits of randomness. Returns `Err` rather
+    /// than panicking if `prefix` doesn't fit (e.g. corrupt or hostile
+    /// wire/DB bytes claiming a longer-than-[`PREFIX_LENGTH`] prefix).
+    pub(crate) fn from_parts(
+        prefix: &str,
+        timestamp: u64,
+        random: u128,
+    ) -> Result<Self, &'static str> {
+        if prefix.len() > PREFIX_LENGTH {
+            return Err("packed Pillid prefix is too long");
+        }
+
+        let mut timestamp_chars = [0u8; TIMESTAMP_LENGTH];
+        let mut random_chars = [0u8; RANDOM_LENGTH];
+        base62_encode(timestamp.into(), &mut timestamp_chars);
+        base62_encode(random, &mut random_chars);
+
+        let rendered = if prefix.is_empty() {
+            format!(
+                "{}{}",
+                std::str::from_utf8(&timestamp_chars).unwrap(),
+                std::str::from_utf8(&random_chars).unwrap()
+            )
+        } else {
+            format!(
+                "{}_{}{}",
+                prefix,
+                std::str::from_utf8(&timestamp_chars).unwrap(),
+                std::str::from_utf8(&random_chars).unwrap()
+            )
+        };
+
+        Pillid::from_str(&rendered).map_err(|_| "packed Pillid does not render to a well-formed Pillid")
+    }
+}
+
+/// A validated, zero-copy view over the segments a rendered Pillid string
+/// encodes: an optional prefix, a timestamp, and 128 bits of randomness.
+///
+/// Where [`PillidBuilder`] is the mutable side of constructing an ID,
+/// `ParsedPillid` is the read-only side of inspecting one: once built, its
+/// getters are infallible and cheap, since the string has already been
+/// checked to be well-formed. [`Pillid::parsed`] relies on this via
+/// [`ParsedPillid::new_unchecked`], so every way of building a `Pillid` from
+/// an arbitrary string — not just [`Pillid::parse`] — has to run this same
+/// validation first; see [`Pillid`]'s [`FromStr`](std::str::FromStr) impl.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParsedPillid<'a> {
+    prefix: Option<&'a str>,
+    timestamp_digits: &'a str,
+    random_digits: &'a str,
+}
+
+impl<'a> ParsedPillid<'a> {
+    /// Splits `s` into its segments without validating them, trusting that
+    /// `s` was rendered by this crate (e.g. via [`Pillid::to_string`]).
+    fn new_unchecked(s: &'a str) -> Self {
+        let (prefix, rest) = match s.find('_') {
+            Some(idx) => (Some(&s[..idx]), &s[idx + 1..]),
+            None => (None, s),
+        };
+        let (timestamp_digits, random_digits) = rest.split_at(TIMESTAMP_LENGTH);
+
+        ParsedPillid {
+            prefix,
+            timestamp_digits,
+            random_digits,
+        }
+    }
+
+    /// Strictly parses `s`, validating its structure instead of assuming it
+    /// was produced by this crate. Rejects the wrong length, characters
+    /// outside [`CHARSET`], and out-of-range timestamps.
+    pub fn parse(s: &'a str) -> Result<Self> {
+        let (prefix, rest) = match s.find('_') {
+            Some(idx) => (Some(&s[..idx]), &s[idx + 1..]),
+            None => (None, s),
+        };
+
+        if let Some(prefix) = prefix {
+            if prefix.len() > PREFIX_LENGTH {
+                return Err(anyhow::anyhow!("Pillid prefix is too long"));
+            }
+        }
+
+        if rest.len() != TIMESTAMP_LENGTH + RANDOM_LENGTH {
+            return Err(anyhow::anyhow!("Pillid is an invalid length"));
+        }
+
+        let (timestamp_digits, random_digits) = rest.split_at(TIMESTAMP_LENGTH);
+
+        let seconds = base62_decode(timestamp_digits.as_bytes())
+            .ok_or_else(|| anyhow::anyhow!("Pillid timestamp contains an invalid character"))?
+            as i64;
+        DateTime::from_timestamp(seconds, 0)
+            .ok_or_else(|| anyhow::anyhow!("Pillid timestamp is out of range"))?;
+
+        base62_decode(random_digits.as_bytes()).ok_or_else(|| {
+            anyhow::anyhow!("Pillid random segment contains an invalid character")
+        })?;
+
+        Ok(ParsedPillid {
+            prefix,
+            timestamp_digits,
+            random_digits,
+        })
+    }
+
+    /// Returns the ID's prefix, or `None` if it was minted without one.
+    pub fn prefix(&self) -> Option<&'a str> {
+        self.prefix
+    }
+
+    /// Returns the time this ID was minted at, to the nearest second.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        let seconds = base62_decode(self.timestamp_digits.as_bytes())
+            .expect("validated on construction") as i64;
+        DateTime::from_timestamp(seconds, 0).expect("validated on construction")
+    }
+
+    /// Returns the 128 bits of randomness this ID was minted with.
+    pub fn random(&self) -> [u8; RANDOMNESS_BYTES] {
+        base62_decode(self.random_digits.as_bytes())
+            .expect("validated on construction")
+            .to_be_bytes()
+    }
 }
 
 impl Default for Pillid {
@@ -82,10 +317,12 @@ impl Debug for Pillid {
 impl FromStr for Pillid {
     type Err = anyhow::Error;
 
+    /// Validates `s` the same way [`ParsedPillid::parse`] does before
+    /// building the `Pillid`. A length-only check here would let a string
+    /// that's merely short enough (e.g. `"hi"`) through, leaving behind a
+    /// `Pillid` that [`Pillid::parsed`] can't actually split into segments.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.as_bytes().len() >= MAX_LENGTH_PILLID {
-            return Err(anyhow::anyhow!("Pillid is too long"));
-        }
+        ParsedPillid::parse(s)?;
 
         let mut bytes: [u8; MAX_LENGTH_PILLID + 1] = unsafe { std::mem::zeroed() };
         bytes[..s.as_bytes().len()].copy_from_slice(s.as_bytes());
@@ -100,23 +337,27 @@ impl From<String> for Pillid {
 }
 
 impl Serialize for Pillid {
-    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(str_from_bytes(&self.0))
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(str_from_bytes(&self.0))
+        } else {
+            serializer.serialize_bytes(&self.to_packed())
+        }
     }
 }
 
 impl<'de> Deserialize<'de> for Pillid {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
-        D: serde::Deserializer<'de>,
+        D: ::serde::Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-
-        if s.as_bytes().len() > MAX_LENGTH_PILLID {
-            return Err(serde::de::Error::custom("Pillid is too long"));
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            s.parse().map_err(::serde::de::Error::custom)
+        } else {
+            let bytes = <Vec<u8>>::deserialize(deserializer)?;
+            Pillid::from_packed(&bytes).map_err(::serde::de::Error::custom)
         }
-
-        Ok(Pillid::from(s))
     }
 }
 
@@ -152,25 +393,14 @@ impl Display for PillidBuilder {
         let mut timestamp_bytes = [0; TIMESTAMP_LENGTH + 1];
         let mut random_bytes = [0; RANDOM_LENGTH + 1];
 
-        fn u128_to_base62_str(n: u128, len: usize, output_buffer: &mut [u8]) {
-            let mut n = n;
-
-            for i in (0..len).rev() {
-                output_buffer[i] = *CHARSET.get((n % 62) as usize).unwrap();
-                n /= 62;
-            }
-        }
-
-        u128_to_base62_str(
+        base62_encode(
             u64::from_be_bytes(*self.timestamp()).into(),
-            TIMESTAMP_LENGTH,
-            &mut timestamp_bytes,
+            &mut timestamp_bytes[..TIMESTAMP_LENGTH],
         );
 
-        u128_to_base62_str(
+        base62_encode(
             u128::from_be_bytes(*self.random()),
-            RANDOM_LENGTH,
-            &mut random_bytes,
+            &mut random_bytes[..RANDOM_LENGTH],
         );
 
         if let Some(prefix) = self.prefix {
@@ -259,103 +489,6 @@ fn str_from_bytes(bytes: &[u8]) -> &str {
         .unwrap()
 }
 
-#[macro_export]
-macro_rules! pillid {
-    ($t:ident, $prefix:expr) => {
-        paste::paste! {
-            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-            pub struct [<$t Pillid>]($crate::Pillid);
-
-            impl [<$t Pillid>] {
-                pub fn new() -> Self {
-                    Self($crate::Pillid::new($prefix))
-                }
-            }
-
-            impl Default for [<$t Pillid>] {
-                fn default() -> Self {
-                    Self::new()
-                }
-            }
-
-            impl std::fmt::Display for [<$t Pillid>] {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    write!(f, "{}", self.0)
-                }
-            }
-
-            impl std::fmt::Debug for [<$t Pillid>] {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    write!(f, "{:?}", self.0)
-                }
-            }
-
-            impl std::str::FromStr for [<$t Pillid>] {
-                type Err = anyhow::Error;
-
-                fn from_str(s: &str) -> Result<Self, Self::Err> {
-                    Ok(Self($crate::Pillid::from_str(s)?))
-                }
-            }
-
-            impl From<String> for [<$t Pillid>] {
-                fn from(s: String) -> Self {
-                    use std::str::FromStr;
-                    Self::from_str(&s).unwrap()
-                }
-            }
-
-            impl std::convert::From<[<$t Pillid>]> for $crate::Pillid {
-                fn from(specialized_pillid: [<$t Pillid>]) -> $crate::Pillid {
-                    specialized_pillid.0
-                }
-            }
-
-            impl std::convert::From<$crate::Pillid> for [<$t Pillid>] {
-                fn from(pillid: $crate::Pillid) -> [<$t Pillid>] {
-                    [<$t Pillid>](pillid)
-                }
-            }
-
-
-            impl Serialize for [<$t Pillid>] {
-                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                    self.0.serialize(serializer)
-                }
-            }
-
-            impl<'de> Deserialize<'de> for [<$t Pillid>] {
-                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-                where
-                    D: serde::Deserializer<'de>,
-                {
-                    $crate::Pillid::deserialize(deserializer).map(Into::into)
-                }
-            }
-
-            #[cfg(feature = "sqlx")]
-            impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for [<$t Pillid>] {
-                fn encode(self, args: &mut Vec<sqlx::sqlite::SqliteArgumentValue<'q>>) -> sqlx::encode::IsNull {
-                    self.0.encode(args)
-                }
-
-                fn encode_by_ref(&self, args: &mut Vec<sqlx::sqlite::SqliteArgumentValue<'q>>) -> sqlx::encode::IsNull {
-                    self.0.encode_by_ref(args)
-                }
-            }
-
-
-            #[cfg(feature = "sqlx")]
-            impl sqlx::Type<sqlx::Sqlite> for [<$t Pillid>] {
-                fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
-                    <&str as sqlx::Type<sqlx::Sqlite>>::type_info()
-                }
-            }
-
-        }
-    };
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -424,6 +557,195 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_pillid_accessors() -> Result<()> {
+        let pillid = Pillid::new("acct");
+
+        assert_eq!(pillid.prefix(), Some("acct"));
+        assert!(pillid.timestamp().timestamp() > 0);
+        assert_ne!(pillid.random(), [0x00; RANDOMNESS_BYTES]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pillid_parse_round_trip() -> Result<()> {
+        let pillid = Pillid::new("acct");
+        let parsed = Pillid::parse(&pillid.to_string())?;
+
+        assert_eq!(parsed.prefix(), pillid.prefix());
+        assert_eq!(parsed.timestamp(), pillid.timestamp());
+        assert_eq!(parsed.random(), pillid.random());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pillid_parse_without_prefix() -> Result<()> {
+        let pillid = PillidBuilder::new().build();
+        let parsed = Pillid::parse(&pillid.to_string())?;
+
+        assert_eq!(parsed.prefix(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pillid_parse_rejects_wrong_length() {
+        assert!(Pillid::parse("acct_tooshort").is_err());
+    }
+
+    #[test]
+    fn test_pillid_parse_with_max_length_prefix() -> Result<()> {
+        let prefix = "a".repeat(PREFIX_LENGTH);
+        let pillid = Pillid::new(&prefix);
+        let parsed = Pillid::parse(&pillid.to_string())?;
+
+        assert_eq!(parsed.prefix(), Some(prefix.as_str()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_rejects_too_short_input() {
+        // Short enough to fit the buffer, but not a well-formed render:
+        // previously this would build a `Pillid` whose accessors panic.
+        assert!("hi".parse::<Pillid>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_rejects_invalid_charset() {
+        assert!("!".repeat(TIMESTAMP_LENGTH + RANDOM_LENGTH)
+            .parse::<Pillid>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_deserialize_human_readable_rejects_malformed_input() {
+        let deserializer =
+            ::serde::de::value::StrDeserializer::<::serde::de::value::Error>::new("hi");
+        let result: std::result::Result<Pillid, _> = Pillid::deserialize(deserializer);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_pillid_parse_rejects_invalid_charset() {
+        let too_long_charset = "!".repeat(TIMESTAMP_LENGTH + RANDOM_LENGTH);
+        assert!(Pillid::parse(&too_long_charset).is_err());
+    }
+
+    #[test]
+    fn test_packed_round_trip_without_prefix() -> Result<()> {
+        let pillid = PillidBuilder::new().build();
+        let unpacked = Pillid::from_packed(&pillid.to_packed()).unwrap();
+
+        assert_eq!(unpacked.prefix(), pillid.prefix());
+        assert_eq!(unpacked.timestamp(), pillid.timestamp());
+        assert_eq!(unpacked.random(), pillid.random());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_packed_does_not_panic_for_a_pillid_built_via_from_str() -> Result<()> {
+        // to_packed() calls self.parsed(), which assumes its bytes are a
+        // well-formed render. Before FromStr validated structure (not just
+        // length), a Pillid obtained this way — e.g. via a human-readable
+        // Deserialize, exactly as a generic serde consumer would produce one
+        // before re-serializing it in binary — could panic here.
+        let pillid: Pillid = "acct_16AHYF7n42DGM5Tflk9n8mt7Fhc7".parse()?;
+        let unpacked = Pillid::from_packed(&pillid.to_packed()).unwrap();
+
+        assert_eq!(unpacked.prefix(), pillid.prefix());
+        assert_eq!(unpacked.timestamp(), pillid.timestamp());
+        assert_eq!(unpacked.random(), pillid.random());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_packed_round_trip_with_short_prefix() -> Result<()> {
+        let pillid = Pillid::new("acct");
+        let unpacked = Pillid::from_packed(&pillid.to_packed()).unwrap();
+
+        assert_eq!(unpacked.prefix(), pillid.prefix());
+        assert_eq!(unpacked.timestamp(), pillid.timestamp());
+        assert_eq!(unpacked.random(), pillid.random());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_packed_round_trip_with_max_length_prefix() -> Result<()> {
+        let prefix = "a".repeat(PREFIX_LENGTH);
+        let pillid = Pillid::new(&prefix);
+        let unpacked = Pillid::from_packed(&pillid.to_packed()).unwrap();
+
+        assert_eq!(unpacked.prefix(), pillid.prefix());
+        assert_eq!(unpacked.timestamp(), pillid.timestamp());
+        assert_eq!(unpacked.random(), pillid.random());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_packed_rejects_oversized_prefix() {
+        let mut packed = vec![(PREFIX_LENGTH + 1) as u8];
+        packed.extend_from_slice(&"a".repeat(PREFIX_LENGTH + 1).into_bytes());
+        packed.extend_from_slice(&[0u8; TIMESTAMP_BYTES]);
+        packed.extend_from_slice(&[0u8; RANDOMNESS_BYTES]);
+
+        assert!(Pillid::from_packed(&packed).is_err());
+    }
+
+    #[test]
+    fn test_parsed_pillid_matches_pillid_accessors() -> Result<()> {
+        let pillid = Pillid::new("acct");
+        let parsed = pillid.parsed();
+
+        assert_eq!(parsed.prefix(), pillid.prefix());
+        assert_eq!(parsed.timestamp(), pillid.timestamp());
+        assert_eq!(parsed.random(), pillid.random());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pillid_accessors_agree_for_a_pillid_built_via_from_str() -> Result<()> {
+        // FromStr runs the same strict validation as Pillid::parse, since
+        // Pillid::parsed() assumes every Pillid's bytes are a well-formed
+        // render: it has to reject anything that wouldn't uphold that, not
+        // just anything too long to fit the buffer.
+        let pillid: Pillid = "acct_16AHYF7n42DGM5Tflk9n8mt7Fhc7".parse()?;
+
+        assert_eq!(pillid.prefix(), Some("acct"));
+        assert!(pillid.timestamp().timestamp() > 0);
+        assert_ne!(pillid.random(), [0x00; RANDOMNESS_BYTES]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parsed_pillid_strict_parse() -> Result<()> {
+        let parsed = ParsedPillid::parse("acct_16AHYF7n42DGM5Tflk9n8mt7Fhc7")?;
+
+        assert_eq!(parsed.prefix(), Some("acct"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parsed_pillid_strict_parse_with_max_length_prefix() -> Result<()> {
+        let prefix = "a".repeat(PREFIX_LENGTH);
+        let pillid = Pillid::new(&prefix);
+        let parsed = ParsedPillid::parse(&pillid.to_string())?;
+
+        assert_eq!(parsed.prefix(), Some(prefix.as_str()));
+
+        Ok(())
+    }
+
     pillid!(Foo, "foo");
 
     #[test]
@@ -433,6 +755,13 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_custom_pillid_parsed() -> Result<()> {
+        let pillid = FooPillid::new();
+        assert_eq!(pillid.parsed().prefix(), Some("foo"));
+        Ok(())
+    }
+
     pillid!(Bar, String::from("bar").as_str());
 
     #[test]
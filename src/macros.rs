@@ -1,3 +1,6 @@
+use serde::Deserialize;
+use serde::Serialize;
+
 #[cfg(feature = "sqlx")]
 #[doc(hidden)]
 #[macro_export]
@@ -19,6 +22,60 @@ macro_rules! sqlx_implementations {
                     <&str as sqlx::Type<sqlx::Sqlite>>::type_info()
                 }
             }
+
+            impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for [<$t Pillid>] {
+                fn decode(
+                    value: <sqlx::Sqlite as sqlx::database::HasValueRef<'r>>::ValueRef,
+                ) -> Result<Self, sqlx::error::BoxDynError> {
+                    Ok(Self(<$crate::Pillid as sqlx::Decode<sqlx::Sqlite>>::decode(value)?))
+                }
+            }
+
+            #[cfg(feature = "postgres")]
+            impl<'q> sqlx::Encode<'q, sqlx::Postgres> for [<$t Pillid>] {
+                fn encode_by_ref(&self, buf: &mut sqlx::postgres::PgArgumentBuffer) -> sqlx::encode::IsNull {
+                    <$crate::Pillid as sqlx::Encode<sqlx::Postgres>>::encode_by_ref(&self.0, buf)
+                }
+            }
+
+            #[cfg(feature = "postgres")]
+            impl sqlx::Type<sqlx::Postgres> for [<$t Pillid>] {
+                fn type_info() -> sqlx::postgres::PgTypeInfo {
+                    <$crate::Pillid as sqlx::Type<sqlx::Postgres>>::type_info()
+                }
+            }
+
+            #[cfg(feature = "postgres")]
+            impl<'r> sqlx::Decode<'r, sqlx::Postgres> for [<$t Pillid>] {
+                fn decode(
+                    value: <sqlx::Postgres as sqlx::database::HasValueRef<'r>>::ValueRef,
+                ) -> Result<Self, sqlx::error::BoxDynError> {
+                    Ok(Self(<$crate::Pillid as sqlx::Decode<sqlx::Postgres>>::decode(value)?))
+                }
+            }
+
+            #[cfg(feature = "mysql")]
+            impl<'q> sqlx::Encode<'q, sqlx::MySql> for [<$t Pillid>] {
+                fn encode_by_ref(&self, buf: &mut sqlx::mysql::MySqlArgumentBuffer) -> sqlx::encode::IsNull {
+                    <$crate::Pillid as sqlx::Encode<sqlx::MySql>>::encode_by_ref(&self.0, buf)
+                }
+            }
+
+            #[cfg(feature = "mysql")]
+            impl sqlx::Type<sqlx::MySql> for [<$t Pillid>] {
+                fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+                    <$crate::Pillid as sqlx::Type<sqlx::MySql>>::type_info()
+                }
+            }
+
+            #[cfg(feature = "mysql")]
+            impl<'r> sqlx::Decode<'r, sqlx::MySql> for [<$t Pillid>] {
+                fn decode(
+                    value: <sqlx::MySql as sqlx::database::HasValueRef<'r>>::ValueRef,
+                ) -> Result<Self, sqlx::error::BoxDynError> {
+                    Ok(Self(<$crate::Pillid as sqlx::Decode<sqlx::MySql>>::decode(value)?))
+                }
+            }
         }
     }
 }
@@ -30,6 +87,34 @@ macro_rules! sqlx_implementations {
     ($t:ident) => {};
 }
 
+#[cfg(feature = "rusqlite")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! rusqlite_implementations {
+    ($t:ident) => {
+        paste::paste! {
+            impl rusqlite::ToSql for [<$t Pillid>] {
+                fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+                    <$crate::Pillid as rusqlite::ToSql>::to_sql(&self.0)
+                }
+            }
+
+            impl rusqlite::types::FromSql for [<$t Pillid>] {
+                fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+                    <$crate::Pillid as rusqlite::types::FromSql>::column_result(value).map(Self)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "rusqlite"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! rusqlite_implementations {
+    ($t:ident) => {};
+}
+
 #[macro_export]
 macro_rules! pillid {
     ($t:ident, $prefix:expr) => {
@@ -41,6 +126,10 @@ macro_rules! pillid {
                 pub fn new() -> Self {
                     Self($crate::Pillid::new($prefix))
                 }
+
+                pub fn parsed(&self) -> $crate::ParsedPillid<'_> {
+                    self.0.parsed()
+                }
             }
 
             impl Default for [<$t Pillid>] {
@@ -105,6 +194,7 @@ macro_rules! pillid {
             }
 
             $crate::sqlx_implementations!($t);
+            $crate::rusqlite_implementations!($t);
         }
     };
 }
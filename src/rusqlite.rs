@@ -0,0 +1,42 @@
+use rusqlite::types::FromSql;
+use rusqlite::types::FromSqlError;
+use rusqlite::types::FromSqlResult;
+use rusqlite::types::ToSqlOutput;
+use rusqlite::types::ValueRef;
+use rusqlite::Result as RusqliteResult;
+use rusqlite::ToSql;
+
+use super::Pillid;
+
+#[cfg(not(feature = "rusqlite-binary"))]
+impl ToSql for Pillid {
+    fn to_sql(&self) -> RusqliteResult<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+#[cfg(not(feature = "rusqlite-binary"))]
+impl FromSql for Pillid {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value
+            .as_str()?
+            .parse::<Pillid>()
+            .map_err(|e| FromSqlError::Other(e.into()))
+    }
+}
+
+/// Stores a Pillid as its packed 24-byte-plus-prefix form in a `BLOB` column
+/// instead of its base62 text, paralleling [`crate::db`]'s `sqlx-binary` feature.
+#[cfg(feature = "rusqlite-binary")]
+impl ToSql for Pillid {
+    fn to_sql(&self) -> RusqliteResult<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_packed()))
+    }
+}
+
+#[cfg(feature = "rusqlite-binary")]
+impl FromSql for Pillid {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        Pillid::from_packed(value.as_blob()?).map_err(|e| FromSqlError::Other(e.into()))
+    }
+}
@@ -0,0 +1,174 @@
+//! Alternative `#[serde(with = "...")]` schemes for [`Pillid`], in the style of `ethnum`'s
+//! `serde` submodules (e.g. `ethnum::serde::bytes::be`). Pick one per field to pin down how a
+//! `Pillid` is (de)serialized regardless of whether the format itself is human-readable; this is
+//! strictly additive to the default [`Serialize`](serde::Serialize)/[`Deserialize`](serde::Deserialize)
+//! impls, which already switch automatically based on [`Serializer::is_human_readable`](serde::Serializer::is_human_readable).
+
+use serde::de::Error as _;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::Pillid;
+use crate::PREFIX_BYTES;
+use crate::RANDOMNESS_BYTES;
+use crate::TIMESTAMP_BYTES;
+
+/// Test helper shared by this module's `string`/`compressed`/`bytes`
+/// submodules, so their round-trip coverage can't silently drift apart.
+#[cfg(test)]
+mod test_support {
+    use crate::Pillid;
+    use crate::PillidBuilder;
+    use crate::PREFIX_LENGTH;
+
+    /// Runs `round_trip` against a no-prefix, short-prefix, and
+    /// max-length-prefix `Pillid`, asserting each result's segments match
+    /// the original.
+    pub(super) fn assert_round_trips(round_trip: impl Fn(Pillid) -> Pillid) {
+        for pillid in [
+            PillidBuilder::new().build(),
+            Pillid::new("acct"),
+            Pillid::new(&"a".repeat(PREFIX_LENGTH)),
+        ] {
+            let unpacked = round_trip(pillid);
+
+            assert_eq!(unpacked.prefix(), pillid.prefix());
+            assert_eq!(unpacked.timestamp(), pillid.timestamp());
+            assert_eq!(unpacked.random(), pillid.random());
+        }
+    }
+}
+
+/// Always (de)serializes as the base62 string, even for binary formats.
+pub mod string {
+    use super::*;
+
+    pub fn serialize<S>(pillid: &Pillid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&pillid.to_string())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Pillid, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Pillid::from)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::serde::test_support::assert_round_trips;
+
+        #[test]
+        fn test_round_trip() {
+            assert_round_trips(|pillid| Pillid::from(pillid.to_string()));
+        }
+    }
+}
+
+/// Always (de)serializes as [`Pillid`]'s variable-length packed form — a prefix-length byte
+/// followed by the trimmed prefix, the 8-byte timestamp, and the 16 random bytes — which is
+/// otherwise only picked automatically for non-human-readable formats.
+pub mod compressed {
+    use super::*;
+
+    pub fn serialize<S>(pillid: &Pillid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&pillid.to_packed())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Pillid, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let packed = <Vec<u8>>::deserialize(deserializer)?;
+        Pillid::from_packed(&packed).map_err(D::Error::custom)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::serde::test_support::assert_round_trips;
+
+        #[test]
+        fn test_round_trip() {
+            assert_round_trips(|pillid| Pillid::from_packed(&pillid.to_packed()).unwrap());
+        }
+    }
+}
+
+/// Always (de)serializes as a fixed-size `[u8; PREFIX_BYTES + 24]`: the prefix zero-padded out
+/// to [`PREFIX_BYTES`], followed by the 8-byte timestamp and the 16 random bytes. Unlike
+/// [`compressed`], the encoded length never depends on the prefix's length, which suits formats
+/// that need a statically-known byte count (e.g. fixed-size columns or arrays).
+pub mod bytes {
+    use super::*;
+
+    const LEN: usize = PREFIX_BYTES + TIMESTAMP_BYTES + RANDOMNESS_BYTES;
+
+    fn to_fixed(pillid: &Pillid) -> [u8; LEN] {
+        let parsed = pillid.parsed();
+        let prefix = parsed.prefix().unwrap_or("");
+        let timestamp = parsed.timestamp().timestamp() as u64;
+        let random = parsed.random();
+
+        let mut fixed = [0u8; LEN];
+        fixed[..prefix.len()].copy_from_slice(prefix.as_bytes());
+        fixed[PREFIX_BYTES..PREFIX_BYTES + TIMESTAMP_BYTES].copy_from_slice(&timestamp.to_be_bytes());
+        fixed[PREFIX_BYTES + TIMESTAMP_BYTES..].copy_from_slice(&random);
+        fixed
+    }
+
+    fn from_fixed(fixed: [u8; LEN]) -> Result<Pillid, &'static str> {
+        let prefix_end = fixed[..PREFIX_BYTES]
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(PREFIX_BYTES);
+        let prefix = std::str::from_utf8(&fixed[..prefix_end])
+            .map_err(|_| "fixed Pillid prefix is not valid UTF-8")?;
+        let timestamp = u64::from_be_bytes(
+            fixed[PREFIX_BYTES..PREFIX_BYTES + TIMESTAMP_BYTES]
+                .try_into()
+                .unwrap(),
+        );
+        let random = u128::from_be_bytes(fixed[PREFIX_BYTES + TIMESTAMP_BYTES..].try_into().unwrap());
+
+        Pillid::from_parts(prefix, timestamp, random)
+    }
+
+    pub fn serialize<S>(pillid: &Pillid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&to_fixed(pillid))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Pillid, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = <Vec<u8>>::deserialize(deserializer)?;
+        let fixed: [u8; LEN] = bytes
+            .try_into()
+            .map_err(|_| D::Error::custom("fixed Pillid bytes have the wrong length"))?;
+
+        from_fixed(fixed).map_err(D::Error::custom)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::serde::test_support::assert_round_trips;
+
+        #[test]
+        fn test_round_trip() {
+            assert_round_trips(|pillid| from_fixed(to_fixed(&pillid)).unwrap());
+        }
+    }
+}